@@ -1316,6 +1316,12 @@ impl Catalog {
                     if is_reserved_name(&schema_name) {
                         return Err(Error::new(ErrorKind::ReservedSchemaName(schema_name)));
                     }
+                    if let Some(reason) = invalid_schema_name_reason(&schema_name) {
+                        return Err(Error::new(ErrorKind::InvalidSchemaName {
+                            name: schema_name,
+                            reason,
+                        }));
+                    }
                     let (database_id, database_name) = match database_name {
                         DatabaseSpecifier::Name(name) => (tx.load_database_id(&name)?, name),
                         DatabaseSpecifier::Ambient => {
@@ -1414,6 +1420,11 @@ impl Catalog {
                     }]
                 }
                 Op::DropRole { name } => {
+                    if name == SYSTEM_USER {
+                        return Err(Error::new(ErrorKind::SystemRoleNotAllowed {
+                            operation: "DROP ROLE".into(),
+                        }));
+                    }
                     tx.remove_role(&name)?;
                     vec![Action::DropRole { name }]
                 }
@@ -1928,6 +1939,27 @@ fn is_reserved_name(name: &str) -> bool {
     name.starts_with("mz_") || name.starts_with("pg_")
 }
 
+/// The maximum length of a schema name in bytes, matching PostgreSQL's
+/// `NAMEDATALEN - 1`.
+pub(crate) const MAX_SCHEMA_NAME_LEN: usize = 63;
+
+/// Reports why `name` cannot be used as a schema name, if it cannot.
+///
+/// Reserved prefixes are checked separately by `is_reserved_name`.
+fn invalid_schema_name_reason(name: &str) -> Option<String> {
+    if name.len() > MAX_SCHEMA_NAME_LEN {
+        Some(format!(
+            "name is {} bytes long, which exceeds the maximum of {}",
+            name.len(),
+            MAX_SCHEMA_NAME_LEN
+        ))
+    } else if name.contains('\0') {
+        Some("name contains a null byte".into())
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Op {
     CreateDatabase {
@@ -562,6 +562,54 @@ pub const MZ_KAFKA_CONSUMER_STATISTICS: BuiltinLog = BuiltinLog {
     index_id: GlobalId::System(3031),
 };
 
+pub const MZ_SCHEDULING_INTERVALS: BuiltinLog = BuiltinLog {
+    name: "mz_scheduling_intervals",
+    schema: MZ_CATALOG_SCHEMA,
+    variant: LogVariant::Timely(TimelyLog::ScheduleIntervals),
+    id: GlobalId::System(3032),
+    index_id: GlobalId::System(3033),
+};
+
+pub const MZ_ARRANGEMENT_MERGE_DURATIONS: BuiltinLog = BuiltinLog {
+    name: "mz_arrangement_merge_durations",
+    schema: MZ_CATALOG_SCHEMA,
+    variant: LogVariant::Differential(DifferentialLog::MergeDurations),
+    id: GlobalId::System(3034),
+    index_id: GlobalId::System(3035),
+};
+
+pub const MZ_WORKER_UTILIZATION: BuiltinLog = BuiltinLog {
+    name: "mz_worker_utilization",
+    schema: MZ_CATALOG_SCHEMA,
+    variant: LogVariant::Timely(TimelyLog::WorkerUtilization),
+    id: GlobalId::System(3036),
+    index_id: GlobalId::System(3037),
+};
+
+pub const MZ_ARRANGEMENT_MERGES: BuiltinLog = BuiltinLog {
+    name: "mz_arrangement_merges",
+    schema: MZ_CATALOG_SCHEMA,
+    variant: LogVariant::Differential(DifferentialLog::ArrangementMerges),
+    id: GlobalId::System(3038),
+    index_id: GlobalId::System(3039),
+};
+
+pub const MZ_OPERATOR_NAME_INDEX: BuiltinLog = BuiltinLog {
+    name: "mz_operator_name_index",
+    schema: MZ_CATALOG_SCHEMA,
+    variant: LogVariant::Timely(TimelyLog::OperatorNameIndex),
+    id: GlobalId::System(3040),
+    index_id: GlobalId::System(3041),
+};
+
+pub const MZ_DATAFLOW_NAME_MAPPING: BuiltinLog = BuiltinLog {
+    name: "mz_dataflow_name_mapping",
+    schema: MZ_CATALOG_SCHEMA,
+    variant: LogVariant::Timely(TimelyLog::DataflowNameMapping),
+    id: GlobalId::System(3042),
+    index_id: GlobalId::System(3043),
+};
+
 lazy_static! {
     pub static ref MZ_VIEW_KEYS: BuiltinTable = BuiltinTable {
         name: "mz_view_keys",
@@ -1280,6 +1328,12 @@ lazy_static! {
             Builtin::Log(&MZ_SOURCE_INFO),
             Builtin::Log(&MZ_MESSAGE_COUNTS),
             Builtin::Log(&MZ_KAFKA_CONSUMER_STATISTICS),
+            Builtin::Log(&MZ_SCHEDULING_INTERVALS),
+            Builtin::Log(&MZ_ARRANGEMENT_MERGE_DURATIONS),
+            Builtin::Log(&MZ_WORKER_UTILIZATION),
+            Builtin::Log(&MZ_ARRANGEMENT_MERGES),
+            Builtin::Log(&MZ_OPERATOR_NAME_INDEX),
+            Builtin::Log(&MZ_DATAFLOW_NAME_MAPPING),
             Builtin::Table(&MZ_VIEW_KEYS),
             Builtin::Table(&MZ_VIEW_FOREIGN_KEYS),
             Builtin::Table(&MZ_KAFKA_SINKS),
@@ -33,6 +33,10 @@ pub(crate) enum ErrorKind {
     RoleAlreadyExists(String),
     ItemAlreadyExists(String),
     ReservedSchemaName(String),
+    InvalidSchemaName {
+        name: String,
+        reason: String,
+    },
     ReservedRoleName(String),
     ReadOnlySystemSchema(String),
     ReadOnlyItem(String),
@@ -56,6 +60,13 @@ pub(crate) enum ErrorKind {
         last_version: usize,
         cause: String,
     },
+    CatalogVersionTooOld {
+        stored: String,
+        supported: String,
+    },
+    SystemRoleNotAllowed {
+        operation: String,
+    },
 }
 
 impl Error {
@@ -72,6 +83,11 @@ impl Error {
             ErrorKind::ReservedSchemaName(_) => {
                 Some("The prefixes \"mz_\" and \"pg_\" are reserved for system schemas.".into())
             }
+            ErrorKind::InvalidSchemaName { .. } => Some(format!(
+                "Schema names must be at most {} bytes long, must not contain null bytes, \
+                 and must not start with \"mz_\" or \"pg_\".",
+                crate::catalog::MAX_SCHEMA_NAME_LEN
+            )),
             ErrorKind::ReservedRoleName(_) => {
                 Some("The prefixes \"mz_\" and \"pg_\" are reserved for system roles.".into())
             }
@@ -81,7 +97,15 @@ impl Error {
 
     /// Reports a hint for the user about how the error could be fixed.
     pub fn hint(&self) -> Option<String> {
-        None
+        match &self.kind {
+            ErrorKind::CatalogVersionTooOld { .. } => {
+                Some("Upgrade Materialize to a version that supports this catalog.".into())
+            }
+            ErrorKind::SystemRoleNotAllowed { .. } => Some(
+                "The mz_system role is reserved for internal use and cannot be modified.".into(),
+            ),
+            _ => None,
+        }
     }
 }
 
@@ -108,6 +132,7 @@ impl std::error::Error for Error {
             | ErrorKind::RoleAlreadyExists(_)
             | ErrorKind::ItemAlreadyExists(_)
             | ErrorKind::ReservedSchemaName(_)
+            | ErrorKind::InvalidSchemaName { .. }
             | ErrorKind::ReservedRoleName(_)
             | ErrorKind::ReadOnlySystemSchema(_)
             | ErrorKind::ReadOnlyItem(_)
@@ -120,7 +145,9 @@ impl std::error::Error for Error {
             | ErrorKind::TypeRename(_)
             | ErrorKind::ExperimentalModeRequired
             | ErrorKind::ExperimentalModeUnavailable
-            | ErrorKind::FailedMigration { .. } => None,
+            | ErrorKind::FailedMigration { .. }
+            | ErrorKind::CatalogVersionTooOld { .. }
+            | ErrorKind::SystemRoleNotAllowed { .. } => None,
             ErrorKind::Sql(e) => Some(e),
             ErrorKind::Storage(e) => Some(e),
         }
@@ -147,6 +174,9 @@ impl fmt::Display for Error {
             ErrorKind::ReservedSchemaName(name) => {
                 write!(f, "unacceptable schema name '{}'", name)
             }
+            ErrorKind::InvalidSchemaName { name, reason } => {
+                write!(f, "invalid schema name '{}': {}", name, reason)
+            }
             ErrorKind::ReservedRoleName(name) => {
                 write!(f, "role name {} is reserved", name.quoted())
             }
@@ -210,6 +240,17 @@ more details, see https://materialize.com/docs/cli#experimental-mode"#
                 "migration from catalog content version {} failed: {}",
                 last_version, cause,
             ),
+            ErrorKind::CatalogVersionTooOld { stored, supported } => write!(
+                f,
+                "catalog was written by version {}, but this binary only supports \
+                 catalogs written by version {} or earlier",
+                stored, supported,
+            ),
+            ErrorKind::SystemRoleNotAllowed { operation } => write!(
+                f,
+                "operation '{}' is not allowed on the mz_system role",
+                operation
+            ),
         }
     }
 }
@@ -151,6 +151,14 @@ impl Connection {
         // Run unapplied migrations. The `user_version` field stores the index
         // of the last migration that was run.
         let version: u32 = sqlite.query_row("PRAGMA user_version", params![], |row| row.get(0))?;
+        // Refuse to open a catalog that was written by a newer version of
+        // materialized, as we cannot know what its migrations changed.
+        if usize::cast_from(version) >= MIGRATIONS.len() {
+            return Err(Error::new(ErrorKind::CatalogVersionTooOld {
+                stored: version.to_string(),
+                supported: (MIGRATIONS.len() - 1).to_string(),
+            }));
+        }
         for (i, sql) in MIGRATIONS
             .iter()
             .enumerate()
@@ -587,3 +595,48 @@ where
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tempfile::NamedTempFile;
+
+    use build_info::DUMMY_BUILD_INFO;
+
+    use super::{Connection, MIGRATIONS};
+    use crate::catalog::config::Config;
+    use crate::catalog::error::ErrorKind;
+
+    #[test]
+    fn test_refuse_newer_catalog() -> Result<(), anyhow::Error> {
+        let catalog_file = NamedTempFile::new()?;
+        let config = Config {
+            path: catalog_file.path(),
+            experimental_mode: Some(false),
+            enable_logging: false,
+            cache_directory: None,
+            build_info: &DUMMY_BUILD_INFO,
+            num_workers: 0,
+            timestamp_frequency: Duration::from_secs(1),
+        };
+
+        // Initialize the catalog, then simulate a newer version of materialized
+        // having applied a migration that this binary does not know about.
+        Connection::open(&config)?;
+        rusqlite::Connection::open(catalog_file.path())?
+            .execute_batch(&format!("PRAGMA user_version = {}", MIGRATIONS.len()))?;
+
+        match Connection::open(&config) {
+            Err(e) => match e.kind {
+                ErrorKind::CatalogVersionTooOld { stored, supported } => {
+                    assert_eq!(stored, MIGRATIONS.len().to_string());
+                    assert_eq!(supported, (MIGRATIONS.len() - 1).to_string());
+                }
+                kind => panic!("unexpected error: {:?}", kind),
+            },
+            Ok(_) => panic!("opened a catalog written by a newer version"),
+        }
+        Ok(())
+    }
+}
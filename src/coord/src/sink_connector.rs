@@ -183,6 +183,7 @@ async fn register_kafka_topic(
     mut partition_count: i32,
     mut replication_factor: i32,
     ccsr: &ccsr::Client,
+    subject_name: &str,
     value_schema: &str,
     key_schema: Option<&str>,
     succeed_if_exists: bool,
@@ -301,13 +302,13 @@ async fn register_kafka_topic(
     // TODO(benesch): do we need to delete the Kafka topic if publishing the
     // schema fails?
     let value_schema_id = ccsr
-        .publish_schema(&format!("{}-value", topic), value_schema)
+        .publish_schema(&format!("{}-value", subject_name), value_schema)
         .await
         .context("unable to publish value schema to registry in kafka sink")?;
 
     let key_schema_id = if let Some(key_schema) = key_schema {
         Some(
-            ccsr.publish_schema(&format!("{}-key", topic), key_schema)
+            ccsr.publish_schema(&format!("{}-key", subject_name), key_schema)
                 .await
                 .context("unable to publish key schema to registry in kafka sink")?,
         )
@@ -348,6 +349,7 @@ async fn build_kafka(
         builder.partition_count,
         builder.replication_factor,
         &ccsr,
+        builder.ccsr_subject_name.as_deref().unwrap_or(&topic),
         &builder.value_schema,
         builder.key_schema.as_deref(),
         builder.exactly_once,
@@ -365,6 +367,7 @@ async fn build_kafka(
             1,
             builder.replication_factor,
             &ccsr,
+            &consistency_topic,
             &consistency_value_schema,
             None,
             builder.exactly_once,
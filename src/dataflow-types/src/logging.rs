@@ -39,12 +39,18 @@ pub enum TimelyLog {
     Addresses,
     Parks,
     Messages,
+    ScheduleIntervals,
+    WorkerUtilization,
+    OperatorNameIndex,
+    DataflowNameMapping,
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub enum DifferentialLog {
     Arrangement,
     Sharing,
+    MergeDurations,
+    ArrangementMerges,
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -115,6 +121,7 @@ impl LogVariant {
                 .with_column("worker", ScalarType::Int64.nullable(false))
                 .with_column("slept_for", ScalarType::Int64.nullable(false))
                 .with_column("requested", ScalarType::Int64.nullable(false))
+                .with_column("timed", ScalarType::Bool.nullable(false))
                 .with_column("count", ScalarType::Int64.nullable(false))
                 .with_key(vec![0, 1, 2]),
 
@@ -126,6 +133,29 @@ impl LogVariant {
                 .with_column("received", ScalarType::Int64.nullable(false))
                 .with_key(vec![0, 1, 2]),
 
+            LogVariant::Timely(TimelyLog::ScheduleIntervals) => RelationDesc::empty()
+                .with_column("id", ScalarType::Int64.nullable(false))
+                .with_column("worker", ScalarType::Int64.nullable(false))
+                .with_column("interval_ns", ScalarType::Int64.nullable(false))
+                .with_column("count", ScalarType::Int64.nullable(false))
+                .with_key(vec![0, 1, 2]),
+
+            LogVariant::Timely(TimelyLog::WorkerUtilization) => RelationDesc::empty()
+                .with_column("worker", ScalarType::Int64.nullable(false))
+                .with_column("active_fraction", ScalarType::Float64.nullable(false))
+                .with_key(vec![0]),
+
+            LogVariant::Timely(TimelyLog::OperatorNameIndex) => RelationDesc::empty()
+                .with_column("name_hash", ScalarType::Int64.nullable(false))
+                .with_column("name", ScalarType::String.nullable(false))
+                .with_key(vec![0, 1]),
+
+            LogVariant::Timely(TimelyLog::DataflowNameMapping) => RelationDesc::empty()
+                .with_column("dataflow", ScalarType::Int64.nullable(false))
+                .with_column("worker", ScalarType::Int64.nullable(false))
+                .with_column("name", ScalarType::String.nullable(false))
+                .with_key(vec![0, 1]),
+
             LogVariant::Differential(DifferentialLog::Arrangement) => RelationDesc::empty()
                 .with_column("operator", ScalarType::Int64.nullable(false))
                 .with_column("worker", ScalarType::Int64.nullable(false))
@@ -139,6 +169,22 @@ impl LogVariant {
                 .with_column("count", ScalarType::Int64.nullable(false))
                 .with_key(vec![0, 1]),
 
+            LogVariant::Differential(DifferentialLog::MergeDurations) => RelationDesc::empty()
+                .with_column("operator", ScalarType::Int64.nullable(false))
+                .with_column("worker", ScalarType::Int64.nullable(false))
+                .with_column("duration_ns", ScalarType::Int64.nullable(false))
+                .with_column("count", ScalarType::Int64.nullable(false))
+                .with_key(vec![0, 1, 2]),
+
+            LogVariant::Differential(DifferentialLog::ArrangementMerges) => RelationDesc::empty()
+                .with_column("operator", ScalarType::Int64.nullable(false))
+                .with_column("worker", ScalarType::Int64.nullable(false))
+                .with_column("merges", ScalarType::Int64.nullable(false))
+                .with_column("input_records", ScalarType::Int64.nullable(false))
+                .with_column("output_records", ScalarType::Int64.nullable(false))
+                .with_column("duration_ns", ScalarType::Int64.nullable(false))
+                .with_key(vec![0, 1]),
+
             LogVariant::Materialized(MaterializedLog::DataflowCurrent) => RelationDesc::empty()
                 .with_column("name", ScalarType::String.nullable(false))
                 .with_column("worker", ScalarType::Int64.nullable(false))
@@ -225,6 +271,13 @@ impl LogVariant {
                     vec![(0, 0), (2, 2)],
                 ),
             ],
+            LogVariant::Timely(TimelyLog::ScheduleIntervals) => vec![(
+                LogVariant::Timely(TimelyLog::Operates),
+                vec![(0, 0), (1, 1)],
+            )],
+            LogVariant::Timely(TimelyLog::WorkerUtilization) => vec![],
+            LogVariant::Timely(TimelyLog::OperatorNameIndex) => vec![],
+            LogVariant::Timely(TimelyLog::DataflowNameMapping) => vec![],
             LogVariant::Differential(DifferentialLog::Arrangement) => vec![(
                 LogVariant::Timely(TimelyLog::Operates),
                 vec![(0, 0), (1, 1)],
@@ -233,6 +286,14 @@ impl LogVariant {
                 LogVariant::Timely(TimelyLog::Operates),
                 vec![(0, 0), (1, 1)],
             )],
+            LogVariant::Differential(DifferentialLog::MergeDurations) => vec![(
+                LogVariant::Timely(TimelyLog::Operates),
+                vec![(0, 0), (1, 1)],
+            )],
+            LogVariant::Differential(DifferentialLog::ArrangementMerges) => vec![(
+                LogVariant::Timely(TimelyLog::Operates),
+                vec![(0, 0), (1, 1)],
+            )],
             LogVariant::Materialized(MaterializedLog::DataflowCurrent) => vec![],
             LogVariant::Materialized(MaterializedLog::DataflowDependency) => vec![],
             LogVariant::Materialized(MaterializedLog::FrontierCurrent) => vec![],
@@ -868,6 +868,9 @@ pub struct KafkaSinkConnectorBuilder {
     pub consistency_value_schema: Option<String>,
     pub config_options: BTreeMap<String, String>,
     pub ccsr_config: ccsr::ClientConfig,
+    /// Overrides the topic name in the subjects under which schemas are
+    /// published to the schema registry.
+    pub ccsr_subject_name: Option<String>,
     pub exactly_once: bool,
 }
 
@@ -9,6 +9,7 @@
 
 //! Logging dataflows for events generated by differential dataflow.
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use differential_dataflow::logging::DifferentialEvent;
@@ -17,94 +18,236 @@ use timely::communication::Allocate;
 use timely::dataflow::operators::capture::EventLink;
 use timely::logging::WorkerIdentifier;
 
-use super::{DifferentialLog, LogVariant};
+use super::{histogram_bucket, DifferentialLog, LogVariant};
 use crate::arrangement::KeysValsHandle;
 use repr::{Datum, Row, Timestamp};
 
+/// The merges an arrangement has completed, remembered so that they can be
+/// retracted when the arrangement is dropped.
+#[derive(Default)]
+struct MergeTotals {
+    /// The number of completed merges.
+    merges: isize,
+    /// The total number of records in the merges' inputs.
+    input_records: isize,
+    /// The total number of records in the merges' outputs.
+    output_records: isize,
+    /// The total duration of the merges.
+    duration_ns: isize,
+    /// The number of merges whose duration fell into each histogram bucket.
+    duration_buckets: HashMap<u128, isize>,
+}
+
 /// Constructs the logging dataflows and returns a logger and trace handles.
 pub fn construct<A: Allocate>(
     worker: &mut timely::worker::Worker<A>,
     config: &dataflow_types::logging::LoggingConfig,
     linked: std::rc::Rc<EventLink<Timestamp, (Duration, WorkerIdentifier, DifferentialEvent)>>,
-) -> std::collections::HashMap<LogVariant, (Vec<usize>, KeysValsHandle)> {
+) -> HashMap<LogVariant, (Vec<usize>, KeysValsHandle)> {
     let granularity_ms = std::cmp::max(1, config.granularity_ns / 1_000_000) as Timestamp;
 
     let traces = worker.dataflow_named("Dataflow: differential logging", move |scope| {
         use differential_dataflow::collection::AsCollection;
         use timely::dataflow::operators::capture::Replay;
-        use timely::dataflow::operators::Map;
 
-        // TODO: Rewrite as one operator with multiple outputs.
         let logs = Some(linked).replay_core(
             scope,
             Some(Duration::from_nanos(config.granularity_ns as u64)),
         );
 
-        // Duration statistics derive from the non-rounded event times.
-        let arrangements = logs
-            .flat_map(move |(ts, worker, event)| {
-                let time_ms = ((ts.as_millis() as Timestamp / granularity_ms) + 1) * granularity_ms;
-                match event {
-                    DifferentialEvent::Batch(event) => {
-                        let difference = (event.length as isize, 1);
-                        Some(((event.operator, worker), time_ms, difference))
-                    }
-                    DifferentialEvent::Merge(event) => {
-                        if let Some(done) = event.complete {
-                            Some((
-                                (event.operator, worker),
-                                time_ms,
-                                (
-                                    (done as isize) - ((event.length1 + event.length2) as isize),
-                                    -1,
-                                ),
-                            ))
-                        } else {
-                            None
+        use timely::dataflow::operators::generic::builder_rc::OperatorBuilder;
+
+        let mut demux =
+            OperatorBuilder::new("Differential Logging Demux".to_string(), scope.clone());
+
+        use timely::dataflow::channels::pact::Pipeline;
+        let mut input = demux.new_input(&logs, Pipeline);
+
+        let (mut arrangements_out, arrangements) = demux.new_output();
+        let (mut sharing_out, sharing) = demux.new_output();
+        let (mut arrangement_merges_out, arrangement_merges) = demux.new_output();
+        let (mut merge_durations_out, merge_durations) = demux.new_output();
+
+        let mut demux_buffer = Vec::new();
+        demux.build(move |_capability| {
+            // Tracks the start time of each in-progress merge so that its
+            // duration can be reported when it completes. A merge is identified
+            // by its operator, worker, and the scale of the batches it merges.
+            let mut merges_data = HashMap::new();
+            // Tracks the number of live handles to each arrangement. An
+            // arrangement whose count drops to zero has been dropped.
+            let mut shares = HashMap::new();
+            // Tracks the merges each live arrangement has completed.
+            let mut merge_totals: HashMap<_, MergeTotals> = HashMap::new();
+            move |_frontiers| {
+                let mut arrangements = arrangements_out.activate();
+                let mut sharing = sharing_out.activate();
+                let mut arrangement_merges = arrangement_merges_out.activate();
+                let mut merge_durations = merge_durations_out.activate();
+
+                input.for_each(|time, data| {
+                    data.swap(&mut demux_buffer);
+
+                    let mut arrangements_session = arrangements.session(&time);
+                    let mut sharing_session = sharing.session(&time);
+                    let mut arrangement_merges_session = arrangement_merges.session(&time);
+                    let mut merge_durations_session = merge_durations.session(&time);
+
+                    for (time, worker, datum) in demux_buffer.drain(..) {
+                        let time_ns = time.as_nanos();
+                        let time_ms = (((time.as_millis() as Timestamp / granularity_ms) + 1)
+                            * granularity_ms) as Timestamp;
+
+                        match datum {
+                            DifferentialEvent::Batch(event) => {
+                                let difference = (event.length as isize, 1);
+                                arrangements_session.give((
+                                    (event.operator, worker),
+                                    time_ms,
+                                    difference,
+                                ));
+                            }
+                            DifferentialEvent::Merge(event) => {
+                                let key = (event.operator, worker, event.scale);
+                                if let Some(done) = event.complete {
+                                    // A completed merge replaces two batches with one.
+                                    let difference = (
+                                        (done as isize)
+                                            - ((event.length1 + event.length2) as isize),
+                                        -1,
+                                    );
+                                    arrangements_session.give((
+                                        (event.operator, worker),
+                                        time_ms,
+                                        difference,
+                                    ));
+                                    if let Some(start_ns) = merges_data.remove(&key) {
+                                        let duration_ns = time_ns - start_ns;
+                                        let bucket = histogram_bucket(duration_ns);
+                                        let input = (event.length1 + event.length2) as isize;
+                                        let output = done as isize;
+                                        arrangement_merges_session.give((
+                                            (event.operator, worker),
+                                            time_ms,
+                                            ((1, duration_ns as isize), (input, output)),
+                                        ));
+                                        merge_durations_session.give((
+                                            ((event.operator, worker), bucket),
+                                            time_ms,
+                                            1,
+                                        ));
+                                        let totals = merge_totals
+                                            .entry((event.operator, worker))
+                                            .or_default();
+                                        totals.merges += 1;
+                                        totals.input_records += input;
+                                        totals.output_records += output;
+                                        totals.duration_ns += duration_ns as isize;
+                                        *totals.duration_buckets.entry(bucket).or_insert(0) += 1;
+                                    }
+                                } else if shares.contains_key(&(event.operator, worker)) {
+                                    // Merges that start after their arrangement has
+                                    // been dropped are not tracked.
+                                    merges_data.insert(key, time_ns);
+                                }
+                            }
+                            DifferentialEvent::Drop(event) => {
+                                let difference = (-(event.length as isize), -1);
+                                arrangements_session.give((
+                                    (event.operator, worker),
+                                    time_ms,
+                                    difference,
+                                ));
+                            }
+                            DifferentialEvent::TraceShare(event) => {
+                                sharing_session.give((
+                                    (event.operator, worker),
+                                    time_ms,
+                                    event.diff,
+                                ));
+
+                                let key = (event.operator, worker);
+                                let handles = shares.entry(key).or_insert(0);
+                                *handles += event.diff;
+                                if *handles == 0 {
+                                    // The arrangement has been dropped. Forget its
+                                    // in-progress merges and retract its merge history.
+                                    shares.remove(&key);
+                                    merges_data.retain(|&(op, w, _), _| (op, w) != key);
+                                    if let Some(totals) = merge_totals.remove(&key) {
+                                        arrangement_merges_session.give((
+                                            key,
+                                            time_ms,
+                                            (
+                                                (-totals.merges, -totals.duration_ns),
+                                                (-totals.input_records, -totals.output_records),
+                                            ),
+                                        ));
+                                        for (bucket, count) in totals.duration_buckets {
+                                            merge_durations_session.give((
+                                                (key, bucket),
+                                                time_ms,
+                                                -count,
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+                            DifferentialEvent::MergeShortfall(_) => {}
                         }
                     }
-                    DifferentialEvent::Drop(event) => {
-                        let difference = (-(event.length as isize), -1);
-                        Some(((event.operator, worker), time_ms, difference))
-                    }
-                    DifferentialEvent::MergeShortfall(_) => None,
-                    DifferentialEvent::TraceShare(_) => None,
-                }
-            })
-            .as_collection()
-            .count_total()
-            .map({
-                move |((op, worker), count)| {
-                    Row::pack_slice(&[
-                        Datum::Int64(op as i64),
-                        Datum::Int64(worker as i64),
-                        Datum::Int64(count.0 as i64),
-                        Datum::Int64(count.1 as i64),
-                    ])
-                }
-            });
-
-        // Duration statistics derive from the non-rounded event times.
-        let sharing = logs
-            .flat_map(move |(ts, worker, event)| {
-                let time_ms = ((ts.as_millis() as Timestamp / granularity_ms) + 1) * granularity_ms;
-                if let DifferentialEvent::TraceShare(event) = event {
-                    Some(((event.operator, worker), time_ms, event.diff))
-                } else {
-                    None
-                }
-            })
-            .as_collection()
-            .count_total()
-            .map({
-                move |((op, worker), count)| {
-                    Row::pack_slice(&[
-                        Datum::Int64(op as i64),
-                        Datum::Int64(worker as i64),
-                        Datum::Int64(count as i64),
-                    ])
-                }
-            });
+                });
+            }
+        });
+
+        let arrangements = arrangements.as_collection().count_total().map({
+            move |((op, worker), count)| {
+                Row::pack_slice(&[
+                    Datum::Int64(op as i64),
+                    Datum::Int64(worker as i64),
+                    Datum::Int64(count.0 as i64),
+                    Datum::Int64(count.1 as i64),
+                ])
+            }
+        });
+
+        let sharing = sharing.as_collection().count_total().map({
+            move |((op, worker), count)| {
+                Row::pack_slice(&[
+                    Datum::Int64(op as i64),
+                    Datum::Int64(worker as i64),
+                    Datum::Int64(count as i64),
+                ])
+            }
+        });
+
+        // Accumulate histograms of merge durations for each operator.
+        let merge_durations = merge_durations.as_collection().count_total().map({
+            move |(((op, worker), pow), count)| {
+                Row::pack_slice(&[
+                    Datum::Int64(op as i64),
+                    Datum::Int64(worker as i64),
+                    Datum::Int64(pow as i64),
+                    Datum::Int64(count as i64),
+                ])
+            }
+        });
+
+        // Accumulate the number, input and output sizes, and total duration of
+        // each operator's merges. The ratio of output to input records shows
+        // how effectively merging compacts the arrangement.
+        let arrangement_merges = arrangement_merges.as_collection().count_total().map({
+            move |((op, worker), ((count, duration_ns), (input, output)))| {
+                Row::pack_slice(&[
+                    Datum::Int64(op as i64),
+                    Datum::Int64(worker as i64),
+                    Datum::Int64(count as i64),
+                    Datum::Int64(input as i64),
+                    Datum::Int64(output as i64),
+                    Datum::Int64(duration_ns as i64),
+                ])
+            }
+        });
 
         let logs = vec![
             (
@@ -112,10 +255,18 @@ pub fn construct<A: Allocate>(
                 arrangements,
             ),
             (LogVariant::Differential(DifferentialLog::Sharing), sharing),
+            (
+                LogVariant::Differential(DifferentialLog::MergeDurations),
+                merge_durations,
+            ),
+            (
+                LogVariant::Differential(DifferentialLog::ArrangementMerges),
+                arrangement_merges,
+            ),
         ];
 
         use differential_dataflow::operators::arrange::arrangement::ArrangeByKey;
-        let mut result = std::collections::HashMap::new();
+        let mut result = HashMap::new();
         for (variant, collection) in logs {
             if config.active_logs.contains_key(&variant) {
                 let key = variant.index_by();
@@ -18,7 +18,7 @@ use timely::dataflow::operators::capture::EventLink;
 use timely::dataflow::operators::generic::operator::Operator;
 use timely::logging::WorkerIdentifier;
 
-use super::{LogVariant, MaterializedLog};
+use super::{histogram_bucket, LogVariant, MaterializedLog};
 use crate::arrangement::KeysValsHandle;
 use expr::{GlobalId, SourceInstanceId};
 use repr::{Datum, Row, Timestamp};
@@ -392,7 +392,7 @@ pub fn construct<A: Allocate>(
                                         let time_ms =
                                             ((time_ms / granularity_ms) + 1) * granularity_ms;
                                         session.give((
-                                            (key.0, elapsed_ns.next_power_of_two()),
+                                            (key.0, histogram_bucket(u128::from(elapsed_ns))),
                                             time_ms,
                                             1isize,
                                         ));
@@ -90,3 +90,40 @@ where
             .push(Event::Progress(vec![(self.time_ms as Timestamp, -1)]));
     }
 }
+
+/// Returns the power-of-two histogram bucket for a duration of `ns` nanoseconds.
+///
+/// Buckets saturate at 2^62, the largest power of two that is representable
+/// in the `Int64` columns of the histogram logs, rather than overflowing.
+pub(crate) fn histogram_bucket(ns: u128) -> u128 {
+    const MAX_BUCKET: u128 = 1 << 62;
+    if ns >= MAX_BUCKET {
+        MAX_BUCKET
+    } else {
+        ns.next_power_of_two()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::histogram_bucket;
+
+    #[test]
+    fn test_histogram_bucket() {
+        assert_eq!(histogram_bucket(0), 1);
+        assert_eq!(histogram_bucket(1), 1);
+        assert_eq!(histogram_bucket(2), 2);
+        assert_eq!(histogram_bucket(3), 4);
+        assert_eq!(histogram_bucket(1 << 20), 1 << 20);
+        assert_eq!(histogram_bucket((1 << 20) + 1), 1 << 21);
+        assert_eq!(histogram_bucket((1 << 62) - 1), 1 << 62);
+        assert_eq!(histogram_bucket(1 << 62), 1 << 62);
+        assert_eq!(histogram_bucket(u128::MAX), 1 << 62);
+        for ns in &[0, 1, 1000, 1 << 40, u128::MAX] {
+            let bucket = histogram_bucket(*ns);
+            assert!(bucket.is_power_of_two());
+            assert!(bucket.trailing_zeros() <= 62);
+            assert!(bucket as i64 > 0);
+        }
+    }
+}
@@ -9,7 +9,9 @@
 
 //! Logging dataflows for events generated by timely dataflow.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::time::Duration;
 
 use differential_dataflow::difference::Abelian;
@@ -22,11 +24,27 @@ use timely::dataflow::operators::capture::EventLink;
 use timely::dataflow::Scope;
 use timely::logging::{ParkEvent, TimelyEvent, WorkerIdentifier};
 
-use super::{LogVariant, TimelyLog};
+use super::{histogram_bucket, LogVariant, TimelyLog};
 use crate::arrangement::KeysValsHandle;
 use dataflow_types::logging::LoggingConfig;
 use repr::{Datum, Row, Timestamp};
 
+/// The age beyond which an operator schedule that has started but not
+/// stopped is presumed to never stop.
+const MAX_SCHEDULE_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Hashes an operator name for `mz_operator_name_index`.
+///
+/// The hash is computed with `DefaultHasher::new()`, i.e. SipHash-1-3 with
+/// zero keys, so it is stable across workers and restarts of the same
+/// binary. The standard library does not guarantee the algorithm across Rust
+/// releases, so hashes should not be persisted outside of Materialize.
+fn operator_name_hash(name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Constructs the logging dataflows and returns a logger and trace handles.
 pub fn construct<A: Allocate>(
     worker: &mut timely::worker::Worker<A>,
@@ -59,6 +77,9 @@ pub fn construct<A: Allocate>(
         let (mut parks_out, parks) = demux.new_output();
         let (mut messages_sent_out, messages_sent) = demux.new_output();
         let (mut messages_received_out, messages_received) = demux.new_output();
+        let (mut utilization_out, utilization) = demux.new_output();
+        let (mut operator_names_out, operator_names) = demux.new_output();
+        let (mut dataflow_names_out, dataflow_names) = demux.new_output();
 
         let mut demux_buffer = Vec::new();
         demux.build(move |_capability| {
@@ -67,7 +88,12 @@ pub fn construct<A: Allocate>(
             // events for the corresponding operators.
             let mut operates_data = HashMap::new();
             let mut channels_data = HashMap::new();
-            let mut parks_data = HashMap::new();
+            // Tracks the park that is currently in progress for each worker, if
+            // any, so that its duration can be reported when it ends.
+            let mut in_flight_parks = HashMap::new();
+            // Tracks when each worker last unparked, so that the time it spent
+            // active before parking again can be reported.
+            let mut last_unparks = HashMap::new();
             move |_frontiers| {
                 let mut operates = operates_out.activate();
                 let mut channels = channels_out.activate();
@@ -75,6 +101,9 @@ pub fn construct<A: Allocate>(
                 let mut parks = parks_out.activate();
                 let mut messages_sent = messages_sent_out.activate();
                 let mut messages_received = messages_received_out.activate();
+                let mut utilization = utilization_out.activate();
+                let mut operator_names = operator_names_out.activate();
+                let mut dataflow_names = dataflow_names_out.activate();
 
                 input.for_each(|time, data| {
                     data.swap(&mut demux_buffer);
@@ -85,6 +114,9 @@ pub fn construct<A: Allocate>(
                     let mut parks_sesssion = parks.session(&time);
                     let mut messages_sent_session = messages_sent.session(&time);
                     let mut messages_received_session = messages_received.session(&time);
+                    let mut utilization_session = utilization.session(&time);
+                    let mut operator_names_session = operator_names.session(&time);
+                    let mut dataflow_names_session = dataflow_names.session(&time);
 
                     for (time, worker, datum) in demux_buffer.drain(..) {
                         let time_ns = time.as_nanos();
@@ -97,6 +129,20 @@ pub fn construct<A: Allocate>(
                                 // version when the operator is dropped.
                                 operates_data.insert((event.id, worker), event.clone());
 
+                                operator_names_session.give((
+                                    (operator_name_hash(&event.name), event.name.clone()),
+                                    time_ms,
+                                    1,
+                                ));
+
+                                // The root operator of a dataflow carries the dataflow's name.
+                                if event.addr.len() == 1 {
+                                    dataflow_names_session.give((
+                                        ((event.addr[0], worker), event.name.clone()),
+                                        time_ms,
+                                        1,
+                                    ));
+                                }
                                 operates_session.give((
                                     ((event.id, worker), event.name),
                                     time_ms,
@@ -156,6 +202,20 @@ pub fn construct<A: Allocate>(
                                 // the `operates` collection, cancelling out the initial
                                 // operator announcement.
                                 if let Some(event) = operates_data.remove(&(event.id, worker)) {
+                                    operator_names_session.give((
+                                        (operator_name_hash(&event.name), event.name.clone()),
+                                        time_ms,
+                                        -1,
+                                    ));
+
+                                    if event.addr.len() == 1 {
+                                        dataflow_names_session.give((
+                                            ((event.addr[0], worker), event.name.clone()),
+                                            time_ms,
+                                            -1,
+                                        ));
+                                    }
+
                                     operates_session.give((
                                         ((event.id, worker), event.name),
                                         time_ms,
@@ -217,10 +277,40 @@ pub fn construct<A: Allocate>(
                             }
                             TimelyEvent::Park(event) => match event {
                                 ParkEvent::Park(duration) => {
-                                    parks_data.insert(worker, (time_ns, duration));
+                                    // A worker cannot park twice without unparking in
+                                    // between. If it appears to have done so, close out
+                                    // the abandoned park as if it ended now, so that its
+                                    // time is not silently dropped from the output.
+                                    if let Some((start_ns, requested)) =
+                                        in_flight_parks.insert(worker, (time_ns, duration))
+                                    {
+                                        log::error!(
+                                            "worker {} parked while already parked",
+                                            worker
+                                        );
+                                        parks_sesssion.give((
+                                            worker,
+                                            time_ns - start_ns,
+                                            requested,
+                                            time_ms,
+                                        ));
+                                        utilization_session.give((
+                                            worker,
+                                            time_ms,
+                                            (0, (time_ns - start_ns) as isize),
+                                        ));
+                                    }
+                                    if let Some(unpark_ns) = last_unparks.remove(&worker) {
+                                        utilization_session.give((
+                                            worker,
+                                            time_ms,
+                                            ((time_ns - unpark_ns) as isize, 0),
+                                        ));
+                                    }
                                 }
                                 ParkEvent::Unpark => {
-                                    if let Some((start_ns, requested)) = parks_data.remove(&worker)
+                                    if let Some((start_ns, requested)) =
+                                        in_flight_parks.remove(&worker)
                                     {
                                         let duration_ns = time_ns - start_ns;
                                         parks_sesssion.give((
@@ -229,6 +319,12 @@ pub fn construct<A: Allocate>(
                                             requested,
                                             time_ms,
                                         ));
+                                        utilization_session.give((
+                                            worker,
+                                            time_ms,
+                                            (0, duration_ns as isize),
+                                        ));
+                                        last_unparks.insert(worker, time_ns);
                                     } else {
                                         panic!("Park data not found!");
                                     }
@@ -279,15 +375,17 @@ pub fn construct<A: Allocate>(
                 timely::dataflow::channels::pact::Pipeline,
                 "Schedules",
                 |_, _| {
-                    let mut map = std::collections::HashMap::new();
+                    let mut map = HashMap::new();
                     let mut vec = Vec::new();
 
                     move |input, output| {
                         input.for_each(|time, data| {
                             data.swap(&mut vec);
                             let mut session = output.session(&time);
+                            let mut latest_ns = None;
                             for (ts, worker, event) in vec.drain(..) {
                                 let time_ns = ts.as_nanos();
+                                latest_ns = Some(time_ns);
                                 let key = (worker, event.id);
                                 match event.start_stop {
                                     timely::logging::StartStop::Start => {
@@ -295,16 +393,75 @@ pub fn construct<A: Allocate>(
                                         map.insert(key, time_ns);
                                     }
                                     timely::logging::StartStop::Stop => {
-                                        assert!(map.contains_key(&key));
-                                        let start = map.remove(&key).expect("start event absent");
-                                        let elapsed_ns = time_ns - start;
-                                        let time_ms = (time_ns / 1_000_000) as Timestamp;
-                                        let time_ms =
-                                            ((time_ms / granularity_ms) + 1) * granularity_ms;
-                                        session.give(((key.1, worker), time_ms, elapsed_ns));
+                                        // The start event may be absent if it was pruned
+                                        // as stale below.
+                                        if let Some(start) = map.remove(&key) {
+                                            let elapsed_ns = time_ns - start;
+                                            let time_ms = (time_ns / 1_000_000) as Timestamp;
+                                            let time_ms =
+                                                ((time_ms / granularity_ms) + 1) * granularity_ms;
+                                            session.give(((key.1, worker), time_ms, elapsed_ns));
+                                        }
                                     }
                                 }
                             }
+                            // Operators that start but never stop (e.g., because their
+                            // dataflow was torn down mid-schedule) would otherwise leave
+                            // their start events in `map` forever.
+                            if let Some(now_ns) = latest_ns {
+                                for (worker, id) in
+                                    prune_stale_schedules(&mut map, now_ns, MAX_SCHEDULE_AGE)
+                                {
+                                    log::warn!(
+                                        "discarding stale schedule start for operator {} on worker {}",
+                                        id,
+                                        worker
+                                    );
+                                }
+                            }
+                        });
+                    }
+                },
+            );
+
+        // Intervals between consecutive schedulings of each operator, which
+        // reveal operators that are starved of scheduling opportunities.
+        let intervals = logs
+            .flat_map(move |(ts, worker, x)| match x {
+                TimelyEvent::Schedule(event) => match event.start_stop {
+                    timely::logging::StartStop::Start => Some((ts, worker, event.id, true)),
+                    timely::logging::StartStop::Stop => None,
+                },
+                TimelyEvent::Shutdown(event) => Some((ts, worker, event.id, false)),
+                _ => None,
+            })
+            .unary(
+                timely::dataflow::channels::pact::Pipeline,
+                "ScheduleIntervals",
+                |_, _| {
+                    let mut last_starts = HashMap::new();
+                    let mut vec = Vec::new();
+
+                    move |input, output| {
+                        input.for_each(|time, data| {
+                            data.swap(&mut vec);
+                            let mut session = output.session(&time);
+                            for (ts, worker, id, is_start) in vec.drain(..) {
+                                let key = (worker, id);
+                                if !is_start {
+                                    // The operator has shut down and will not be
+                                    // scheduled again.
+                                    last_starts.remove(&key);
+                                    continue;
+                                }
+                                let time_ns = ts.as_nanos();
+                                if let Some(last_ns) = last_starts.insert(key, time_ns) {
+                                    let time_ms = (time_ns / 1_000_000) as Timestamp;
+                                    let time_ms =
+                                        ((time_ms / granularity_ms) + 1) * granularity_ms;
+                                    session.give(((id, worker), time_ms, time_ns - last_ns));
+                                }
+                            }
                         });
                     }
                 },
@@ -328,7 +485,7 @@ pub fn construct<A: Allocate>(
 
         // Accumulate histograms of execution times for each operator.
         let mut histogram = duration
-            .map(|(op_worker, t, d)| ((op_worker, d.next_power_of_two()), t, 1isize))
+            .map(|(op_worker, t, d)| ((op_worker, histogram_bucket(d)), t, 1isize))
             .as_collection();
         // Remove histogram measurements for operators not present in `operates`.
         histogram = thin_collection(histogram, delay, |c| c.semijoin(&operates.map(|(k, _)| k)));
@@ -336,6 +493,18 @@ pub fn construct<A: Allocate>(
             .count_total()
             .map(|((key, pow), count)| ((key), (pow, count)));
 
+        // Accumulate histograms of the intervals between schedulings of each operator.
+        let mut schedule_intervals = intervals
+            .map(|(op_worker, t, d)| ((op_worker, histogram_bucket(d)), t, 1isize))
+            .as_collection();
+        // Remove interval measurements for operators not present in `operates`.
+        schedule_intervals = thin_collection(schedule_intervals, delay, |c| {
+            c.semijoin(&operates.map(|(k, _)| k))
+        });
+        let schedule_intervals = schedule_intervals
+            .count_total()
+            .map(|((key, pow), count)| ((key), (pow, count)));
+
         let elapsed = elapsed.map({
             move |((id, worker), cnt)| {
                 Row::pack_slice(&[
@@ -357,6 +526,17 @@ pub fn construct<A: Allocate>(
             }
         });
 
+        let schedule_intervals = schedule_intervals.map({
+            move |((id, worker), (pow, cnt))| {
+                Row::pack_slice(&[
+                    Datum::Int64(id as i64),
+                    Datum::Int64(worker as i64),
+                    Datum::Int64(pow as i64),
+                    Datum::Int64(cnt as i64),
+                ])
+            }
+        });
+
         let operates = operates.map({
             move |((id, worker), name)| {
                 Row::pack_slice(&[
@@ -370,13 +550,15 @@ pub fn construct<A: Allocate>(
         let addresses = addresses.as_collection();
         let channels = channels.as_collection();
 
+        // A park with a requested duration is a timed wait (e.g., for a timer to
+        // fire), whereas a park without one waits indefinitely for new input.
         let parks = parks
             .map(|(w, d, r, t)| {
                 (
                     (
                         w,
-                        d.next_power_of_two(),
-                        r.map(|r| r.as_nanos().next_power_of_two()),
+                        histogram_bucket(d),
+                        r.map(|r| histogram_bucket(r.as_nanos())),
                     ),
                     t,
                     1,
@@ -390,11 +572,47 @@ pub fn construct<A: Allocate>(
                         Datum::Int64(w as i64),
                         Datum::Int64(d as i64),
                         r.map(|r| Datum::Int64(r as i64)).unwrap_or(Datum::Null),
+                        Datum::from(r.is_some()),
                         Datum::Int64(c),
                     ])
                 }
             });
 
+        // Accumulate the time each worker has spent active and parked, and
+        // report the fraction of that time it was active.
+        let utilization = utilization.as_collection().count_total().map({
+            move |(worker, (active_ns, parked_ns))| {
+                let total_ns = active_ns + parked_ns;
+                let active_fraction = if total_ns > 0 {
+                    active_ns as f64 / total_ns as f64
+                } else {
+                    0.0
+                };
+                Row::pack_slice(&[
+                    Datum::Int64(worker as i64),
+                    Datum::Float64(active_fraction.into()),
+                ])
+            }
+        });
+
+        // Many operators share a name, so only report each distinct name once
+        // for as long as at least one operator with that name exists.
+        let operator_names = operator_names.as_collection().count_total().map({
+            move |((name_hash, name), _count)| {
+                Row::pack_slice(&[Datum::Int64(name_hash as i64), Datum::String(&name)])
+            }
+        });
+
+        let dataflow_names = dataflow_names.as_collection().map({
+            move |((dataflow, worker), name)| {
+                Row::pack_slice(&[
+                    Datum::Int64(dataflow as i64),
+                    Datum::Int64(worker as i64),
+                    Datum::String(&name),
+                ])
+            }
+        });
+
         use differential_dataflow::operators::Count;
         let messages_sent = thin_collection(messages_sent.as_collection(), delay, |c| {
             c.semijoin(&channels.map(|(k, _, _, _, _)| k))
@@ -450,6 +668,19 @@ pub fn construct<A: Allocate>(
             (LogVariant::Timely(TimelyLog::Addresses), addresses),
             (LogVariant::Timely(TimelyLog::Parks), parks),
             (LogVariant::Timely(TimelyLog::Messages), messages),
+            (
+                LogVariant::Timely(TimelyLog::ScheduleIntervals),
+                schedule_intervals,
+            ),
+            (LogVariant::Timely(TimelyLog::WorkerUtilization), utilization),
+            (
+                LogVariant::Timely(TimelyLog::OperatorNameIndex),
+                operator_names,
+            ),
+            (
+                LogVariant::Timely(TimelyLog::DataflowNameMapping),
+                dataflow_names,
+            ),
         ];
 
         let mut result = std::collections::HashMap::new();
@@ -477,6 +708,25 @@ pub fn construct<A: Allocate>(
     traces
 }
 
+/// Removes the schedule starts in `starts` that are more than `max_age` older
+/// than `now_ns`, returning the `(worker, operator)` keys that were removed.
+fn prune_stale_schedules(
+    starts: &mut HashMap<(WorkerIdentifier, usize), u128>,
+    now_ns: u128,
+    max_age: Duration,
+) -> Vec<(WorkerIdentifier, usize)> {
+    let cutoff_ns = now_ns.saturating_sub(max_age.as_nanos());
+    let stale: Vec<_> = starts
+        .iter()
+        .filter(|(_, start_ns)| **start_ns < cutoff_ns)
+        .map(|(key, _)| *key)
+        .collect();
+    for key in &stale {
+        starts.remove(key);
+    }
+    stale
+}
+
 /// Discard all of the records in `c` that `logic` doesn't care about (return).
 fn thin_collection<G, D, R, F>(
     c: Collection<G, D, R>,
@@ -499,3 +749,48 @@ where
     retractions.set(&c.concat(&result.negate()));
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use super::prune_stale_schedules;
+
+    #[test]
+    fn test_prune_stale_schedules() {
+        let max_age = Duration::from_secs(10);
+        let max_age_ns = max_age.as_nanos();
+        let now_ns = 100 * max_age_ns;
+        let cutoff_ns = now_ns - max_age_ns;
+
+        let mut starts = HashMap::new();
+        starts.insert((0, 1), 0);
+        starts.insert((0, 2), cutoff_ns - 1);
+        starts.insert((1, 2), cutoff_ns);
+        starts.insert((1, 3), cutoff_ns + 1);
+        starts.insert((2, 4), now_ns);
+
+        let mut stale = prune_stale_schedules(&mut starts, now_ns, max_age);
+        stale.sort();
+        assert_eq!(stale, vec![(0, 1), (0, 2)]);
+        let mut kept: Vec<_> = starts.into_iter().collect();
+        kept.sort();
+        assert_eq!(
+            kept,
+            vec![
+                ((1, 2), cutoff_ns),
+                ((1, 3), cutoff_ns + 1),
+                ((2, 4), now_ns)
+            ]
+        );
+
+        // Before `max_age` has passed, the cutoff saturates at zero and
+        // nothing is stale.
+        let mut starts = HashMap::new();
+        starts.insert((0, 1), 0);
+        starts.insert((0, 2), max_age_ns / 2);
+        assert!(prune_stale_schedules(&mut starts, max_age_ns - 1, max_age).is_empty());
+        assert_eq!(starts.len(), 2);
+    }
+}
@@ -32,6 +32,9 @@ enum ValType {
     // Number with range [lower, upper]
     Number(i32, i32),
     Boolean,
+    // String or number that must match one of the listed values
+    // (case-insensitively)
+    OneOf(&'static [&'static str]),
 }
 
 // Describes Kafka cluster configurations users can suppply using `CREATE
@@ -41,6 +44,7 @@ struct Config {
     name: &'static str,
     val_type: ValType,
     transform: fn(String) -> String,
+    validate: Option<fn(&str) -> Result<(), anyhow::Error>>,
     default: Option<String>,
 }
 
@@ -50,6 +54,7 @@ impl Config {
             name,
             val_type,
             transform: convert::identity,
+            validate: None,
             default: None,
         }
     }
@@ -71,6 +76,13 @@ impl Config {
         self
     }
 
+    // Builds a new config that additionally checks the parameter with `f` after
+    // its type is validated.
+    fn validate(mut self, f: fn(&str) -> Result<(), anyhow::Error>) -> Self {
+        self.validate = Some(f);
+        self
+    }
+
     // Allows for returning a default value for this configuration option
     fn set_default(mut self, d: Option<String>) -> Self {
         self.default = d;
@@ -96,8 +108,19 @@ impl Config {
                 Ok(parsed_n) if *lower <= parsed_n && parsed_n <= *upper => n.to_string(),
                 _ => bail!("must be a number between {} and {}", lower, upper),
             },
+            (ValType::OneOf(allowed), Value::String(v))
+            | (ValType::OneOf(allowed), Value::Number(v)) => {
+                let v = v.to_lowercase();
+                if !allowed.contains(&v.as_str()) {
+                    bail!("must be one of {}", allowed.join(", "))
+                }
+                v
+            }
             _ => bail!("unexpected value type"),
         };
+        if let Some(validate) = self.validate {
+            validate(&val)?;
+        }
         Ok((self.transform)(val))
     }
 }
@@ -135,14 +158,48 @@ fn extract(
 /// expected file paths.
 /// - If any of the values in `with_options` are not
 ///   `sql_parser::ast::Value::String`.
+/// - If `ssl_cipher_suites` or `ssl_endpoint_identification_algorithm` is
+///   specified without an SSL `security_protocol`.
 pub fn extract_config(
     with_options: &mut BTreeMap<String, Value>,
 ) -> Result<BTreeMap<String, String>, anyhow::Error> {
-    extract(
+    let mut config = extract(
         with_options,
         &[
-            Config::string("acks"),
+            Config::new("acks", ValType::OneOf(&["0", "1", "all", "-1"])),
+            Config::new(
+                "auto_offset_reset",
+                ValType::OneOf(&["earliest", "latest", "error"]),
+            ),
+            Config::new(
+                "broker_address_family",
+                ValType::OneOf(&["any", "v4", "v6"]),
+            ),
+            Config::new(
+                "broker_address_ttl",
+                // The range of values comes from `broker.address.ttl` in
+                // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
+                ValType::Number(0, 86_400_000),
+            ),
+            Config::string("broker_version_fallback").validate(validate_broker_version),
             Config::string("client_id"),
+            Config::new(
+                "compression_codec",
+                ValType::OneOf(&["none", "gzip", "snappy", "lz4", "zstd"]),
+            ),
+            Config::new(
+                "fetch_min_bytes",
+                // librdkafka accepts values up to 100,000,000, but fetch sizes
+                // beyond 1 MiB only add latency without improving batching.
+                ValType::Number(1, 1_048_576),
+            ),
+            Config::new(
+                "fetch_wait_max_ms",
+                // librdkafka accepts values up to 300,000, but making the broker
+                // hold fetch requests for more than 30 seconds only delays
+                // ingestion.
+                ValType::Number(0, 30_000),
+            ),
             Config::new(
                 "statistics_interval_ms",
                 // The range of values comes from `statistics.interval.ms` in
@@ -158,8 +215,52 @@ pub fn extract_config(
                 // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
                 ValType::Number(0, 3_600_000),
             ),
+            Config::new(
+                "metadata_max_age_ms",
+                // librdkafka accepts values up to 86,400,000, but broker
+                // metadata older than ten minutes is too stale to be useful in
+                // clusters whose topology changes.
+                ValType::Number(1, 600_000),
+            ),
+            Config::new(
+                "message_send_max_retries",
+                // The range of values comes from `message.send.max.retries` in
+                // https://github.com/edenhill/librdkafka/blob/v1.6.1/CONFIGURATION.md
+                ValType::Number(0, 10_000_000),
+            ),
+            Config::new(
+                "retry_backoff_ms",
+                // The lower bound is raised from librdkafka's 1ms to avoid
+                // hammering unavailable brokers with retries.
+                ValType::Number(1, 300_000),
+            ),
+            Config::new(
+                "linger_ms",
+                // librdkafka accepts values up to 900,000, but holding messages
+                // back for more than five minutes only delays the sink.
+                ValType::Number(0, 300_000),
+            ),
             Config::new("enable_auto_commit", ValType::Boolean),
+            Config::new("enable_idempotence", ValType::Boolean),
+            Config::new(
+                "isolation_level",
+                ValType::OneOf(&["read_committed", "read_uncommitted"]),
+            ),
             Config::string("security_protocol"),
+            Config::new(
+                "socket_receive_buffer_bytes",
+                // The range of values comes from `socket.receive.buffer.bytes` in
+                // https://github.com/edenhill/librdkafka/blob/v1.6.1/CONFIGURATION.md
+                ValType::Number(0, 100_000_000),
+            )
+            .validate(validate_socket_buffer_size),
+            Config::new(
+                "socket_send_buffer_bytes",
+                // The range of values comes from `socket.send.buffer.bytes` in
+                // https://github.com/edenhill/librdkafka/blob/v1.6.1/CONFIGURATION.md
+                ValType::Number(0, 100_000_000),
+            )
+            .validate(validate_socket_buffer_size),
             Config::path("sasl_kerberos_keytab"),
             Config::string("sasl_username"),
             Config::string("sasl_password"),
@@ -173,10 +274,101 @@ pub fn extract_config(
             Config::string("sasl_mechanisms").transform(|s| s.to_uppercase()),
             Config::path("ssl_ca_location"),
             Config::path("ssl_certificate_location"),
+            Config::string("ssl_cipher_suites").validate(validate_cipher_suites),
+            Config::new(
+                "ssl_endpoint_identification_algorithm",
+                ValType::OneOf(&["none", "https"]),
+            ),
             Config::path("ssl_key_location"),
             Config::string("ssl_key_password"),
         ],
-    )
+    )?;
+
+    let uses_ssl = config
+        .get("security.protocol")
+        .map(|p| p.to_lowercase().contains("ssl"))
+        .unwrap_or(false);
+
+    if let Some(suites) = config.get("ssl.cipher.suites") {
+        if !uses_ssl {
+            bail!("ssl_cipher_suites requires security_protocol to be ssl or sasl_ssl");
+        }
+        for suite in insecure_cipher_suites(suites) {
+            warn!("ssl_cipher_suites includes insecure cipher suite {}", suite);
+        }
+    }
+
+    // librdkafka 1.6.1 does not verify broker hostnames by default, so
+    // request verification explicitly unless the user has opted out.
+    match config.get("ssl.endpoint.identification.algorithm") {
+        Some(_) if !uses_ssl => bail!(
+            "ssl_endpoint_identification_algorithm requires security_protocol to be ssl or sasl_ssl"
+        ),
+        Some(algorithm) if algorithm == "none" => {
+            warn!(
+                "ssl_endpoint_identification_algorithm is none; broker hostnames are not verified"
+            )
+        }
+        Some(_) => (),
+        None if uses_ssl => {
+            config.insert(
+                "ssl.endpoint.identification.algorithm".to_string(),
+                "https".to_string(),
+            );
+        }
+        None => (),
+    }
+
+    Ok(config)
+}
+
+// Validates that a socket buffer size is page aligned. Zero, which selects the
+// operating system's default, is also accepted.
+fn validate_socket_buffer_size(v: &str) -> Result<(), anyhow::Error> {
+    match v.parse::<i32>() {
+        Ok(n) if n % 4096 == 0 => Ok(()),
+        _ => bail!("must be a multiple of 4096"),
+    }
+}
+
+// Substrings of OpenSSL cipher suite names that indicate broken or
+// deliberately weakened ciphers.
+const INSECURE_CIPHER_MARKERS: &[&str] = &["NULL", "EXPORT", "RC4", "3DES", "DES-CBC", "MD5"];
+
+// Returns the entries of an OpenSSL cipher list that select insecure cipher
+// suites. Entries prefixed with `!` or `-` remove suites from the list rather
+// than adding them, so they are never insecure.
+fn insecure_cipher_suites(suites: &str) -> Vec<&str> {
+    suites
+        .split(':')
+        .filter(|suite| !suite.starts_with('!') && !suite.starts_with('-'))
+        .filter(|suite| {
+            let upper = suite.to_uppercase();
+            INSECURE_CIPHER_MARKERS.iter().any(|m| upper.contains(m))
+        })
+        .collect()
+}
+
+// Validates an OpenSSL cipher list such as
+// `ECDHE-RSA-AES128-GCM-SHA256:ECDHE-RSA-AES256-GCM-SHA384`.
+fn validate_cipher_suites(v: &str) -> Result<(), anyhow::Error> {
+    if v.split(':').any(|suite| suite.trim().is_empty()) {
+        bail!("must be a non-empty, colon-separated list of cipher suites")
+    }
+    Ok(())
+}
+
+// Validates a Kafka broker version such as `0.9.0` or `0.8.2.2`.
+fn validate_broker_version(v: &str) -> Result<(), anyhow::Error> {
+    let parts: Vec<_> = v.split('.').collect();
+    if !(3..=4).contains(&parts.len())
+        || parts
+            .iter()
+            .any(|p| p.is_empty() || !p.chars().all(|c| c.is_ascii_digit()))
+    {
+        bail!("must be a version number like 0.9.0 or 0.8.2.2")
+    }
+    Ok(())
 }
 
 /// Create a new `rdkafka::ClientConfig` with the provided
@@ -282,6 +474,39 @@ impl ClientContext for KafkaErrCheckContext {
     }
 }
 
+/// Extracts the Confluent Schema Registry subject name override, if any, from
+/// the `WITH` options of a `CONFLUENT SCHEMA REGISTRY` clause.
+///
+/// Schemas are looked up and published under the subjects `<name>-key` and
+/// `<name>-value`, where `<name>` is the Kafka topic name unless overridden by
+/// the `subject_name` option.
+///
+/// # Errors
+///
+/// - If `subject_name` is not a string, is empty, or contains characters that
+///   are illegal in subject names (`/`, `\`, or null bytes).
+pub fn extract_ccsr_subject_name(
+    ccsr_options: &BTreeMap<String, Value>,
+) -> Result<Option<String>, anyhow::Error> {
+    match ccsr_options.get("subject_name") {
+        None => Ok(None),
+        Some(Value::String(name)) => {
+            if name.is_empty() || name.contains(|c| c == '/' || c == '\\' || c == '\0') {
+                bail!(
+                    "Invalid WITH option subject_name='{}': must be non-empty and must not \
+                     contain '/', '\\', or null bytes",
+                    name
+                )
+            }
+            Ok(Some(name.clone()))
+        }
+        Some(v) => bail!(
+            "Invalid WITH option subject_name={}: unexpected value type",
+            v
+        ),
+    }
+}
+
 // Generates a `ccsr::ClientConfig` based on the configuration extracted from
 // `extract_security_config()`. Currently only supports SSL auth.
 pub fn generate_ccsr_client_config(
@@ -329,3 +554,26 @@ pub fn generate_ccsr_client_config(
 
     Ok(client_config)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insecure_cipher_suites() {
+        let test_cases = vec![
+            ("ECDHE-RSA-AES128-GCM-SHA256", vec![]),
+            ("HIGH:!aNULL:!MD5", vec![]),
+            ("ECDHE-RSA-AES256-GCM-SHA384:-3DES", vec![]),
+            (
+                "DES-CBC3-SHA:ECDHE-RSA-AES128-GCM-SHA256",
+                vec!["DES-CBC3-SHA"],
+            ),
+            ("HIGH:aNULL:!MD5", vec!["aNULL"]),
+            ("rc4-md5", vec!["rc4-md5"]),
+        ];
+        for (suites, expected) in test_cases {
+            assert_eq!(insecure_cipher_suites(suites), expected, "{}", suites);
+        }
+    }
+}
@@ -525,6 +525,26 @@ pub fn plan_create_source(
     let (external_connector, mut encoding) = match connector {
         Connector::Kafka { broker, topic, .. } => {
             let config_options = kafka_util::extract_config(&mut with_options)?;
+            if let Some(version) = config_options.get("broker.version.fallback") {
+                warn!(
+                    "Kafka source uses broker_version_fallback={}; API version \
+                     negotiation and newer broker features will be unavailable",
+                    version
+                );
+            }
+            if config_options.get("auto.offset.reset").map(String::as_str) == Some("latest") {
+                warn!(
+                    "Kafka source uses auto_offset_reset=latest; data will be skipped \
+                     if the source's offsets are no longer available"
+                );
+            }
+            if config_options.get("isolation.level").map(String::as_str) == Some("read_uncommitted")
+            {
+                warn!(
+                    "Kafka source uses isolation_level=read_uncommitted; records from \
+                     aborted transactions will be ingested"
+                );
+            }
 
             consistency = match with_options.remove("consistency") {
                 None => Consistency::RealTime,
@@ -534,7 +554,14 @@ pub fn plan_create_source(
 
             let group_id_prefix = match with_options.remove("group_id_prefix") {
                 None => None,
-                Some(Value::String(s)) => Some(s),
+                Some(Value::String(s)) => {
+                    // librdkafka passes the group ID to the broker as a C
+                    // string, so a null byte would silently truncate it.
+                    if s.contains('\0') {
+                        bail!("group_id_prefix must not contain null bytes");
+                    }
+                    Some(s)
+                }
                 Some(_) => bail!("group_id_prefix must be a string"),
             };
 
@@ -1208,6 +1235,40 @@ fn kafka_sink_builder(
     };
 
     let config_options = kafka_util::extract_config(with_options)?;
+
+    // Kafka sinks enable the idempotent producer unless the user opts out, and
+    // the idempotent producer requires acknowledgement from all replicas.
+    let idempotent = config_options.get("enable.idempotence").map(String::as_str) != Some("false");
+    match config_options.get("acks").map(String::as_str) {
+        Some(acks @ "0") | Some(acks @ "1") if idempotent => {
+            bail!("acks={} requires enable_idempotence to be false", acks)
+        }
+        Some("0") => warn!("Kafka sink uses acks=0; messages may be lost without error"),
+        _ => (),
+    }
+    // The idempotent producer also requires at least one retry. librdkafka
+    // would otherwise only reject the combination when a worker creates the
+    // sink's producer, which is not recoverable.
+    let retries = config_options.get("message.send.max.retries");
+    if idempotent && retries.map(String::as_str) == Some("0") {
+        bail!("message_send_max_retries=0 requires enable_idempotence to be false");
+    }
+    // Without idempotence, retries can write duplicate messages, which an
+    // exactly-once sink must not do.
+    if exactly_once && !idempotent {
+        bail!("exactly-once Kafka sinks require enable_idempotence to be true");
+    }
+    // Kafka recommends that idempotent producers linger for at least 5ms so
+    // that messages are batched rather than sent one request at a time.
+    if let Some(linger_ms) = config_options.get("linger.ms") {
+        if idempotent && linger_ms.parse::<i32>()? < 5 {
+            bail!(
+                "linger_ms={} must be at least 5 when enable_idempotence is true",
+                linger_ms
+            )
+        }
+    }
+    let ccsr_subject_name = kafka_util::extract_ccsr_subject_name(&ccsr_with_options)?;
     let ccsr_config = kafka_util::generate_ccsr_client_config(
         schema_registry_url.clone(),
         &config_options,
@@ -1225,6 +1286,7 @@ fn kafka_sink_builder(
         consistency_value_schema,
         config_options,
         ccsr_config,
+        ccsr_subject_name,
         key_schema,
         key_desc_and_indices,
         value_desc,
@@ -23,7 +23,7 @@ use tokio::time::Duration;
 use repr::strconv;
 use sql_parser::ast::{
     AvroSchema, ColumnOption, ColumnOptionDef, Connector, CreateSourceStatement, CsrSeed, Format,
-    Ident, Raw, Statement,
+    Ident, Raw, Statement, Value,
 };
 use sql_parser::parser::parse_columns;
 
@@ -60,6 +60,15 @@ pub async fn purify(mut stmt: Statement<Raw>) -> Result<Statement<Raw>, anyhow::
                     *broker += ":9092";
                 }
 
+                // Sources created before the length limit was introduced are
+                // not purified again when the catalog is loaded, so enforcing
+                // it here does not prevent them from loading.
+                if let Some(Value::String(prefix)) = with_options_map.get("group_id_prefix") {
+                    if prefix.chars().count() > 100 {
+                        bail!("group_id_prefix must be at most 100 characters");
+                    }
+                }
+
                 // Verify that the provided security options are valid and then test them.
                 config_options = kafka_util::extract_config(&mut with_options_map)?;
                 kafka_util::test_config(&broker, &config_options).await?;
@@ -191,12 +200,15 @@ async fn purify_format(
                 };
                 if seed.is_none() {
                     let url = url.parse()?;
+                    let ccsr_options = normalize::options(ccsr_options);
+                    let subject_name = kafka_util::extract_ccsr_subject_name(&ccsr_options)?
+                        .unwrap_or_else(|| topic.clone());
 
                     let ccsr_config = task::block_in_place(|| {
                         kafka_util::generate_ccsr_client_config(
                             url,
                             &connector_options,
-                            normalize::options(ccsr_options),
+                            ccsr_options,
                         )
                     })?;
 
@@ -204,7 +216,7 @@ async fn purify_format(
                         key_schema,
                         value_schema,
                         ..
-                    } = get_remote_avro_schema(ccsr_config, topic.clone()).await?;
+                    } = get_remote_avro_schema(ccsr_config, subject_name).await?;
                     *seed = Some(CsrSeed {
                         key_schema,
                         value_schema,
@@ -268,11 +280,11 @@ pub struct Schema {
 
 async fn get_remote_avro_schema(
     schema_registry_config: ccsr::ClientConfig,
-    topic: String,
+    subject_name: String,
 ) -> Result<Schema, anyhow::Error> {
     let ccsr_client = schema_registry_config.clone().build()?;
 
-    let value_schema_name = format!("{}-value", topic);
+    let value_schema_name = format!("{}-value", subject_name);
     let value_schema = ccsr_client
         .get_schema_by_subject(&value_schema_name)
         .await
@@ -282,7 +294,7 @@ async fn get_remote_avro_schema(
                 value_schema_name
             )
         })?;
-    let subject = format!("{}-key", topic);
+    let subject = format!("{}-key", subject_name);
     let key_schema = ccsr_client.get_schema_by_subject(&subject).await.ok();
     Ok(Schema {
         key_schema: key_schema.map(|s| s.raw),
@@ -28,6 +28,7 @@ pub enum SinkConsistencyFormat {
 pub struct VerifyAction {
     sink: String,
     consistency: Option<SinkConsistencyFormat>,
+    subject: Option<String>,
     expected_messages: Vec<String>,
 }
 
@@ -39,12 +40,14 @@ pub fn build_verify(mut cmd: BuiltinCommand) -> Result<VerifyAction, String> {
         Some(s) => return Err(format!("unknown sink consistency format {}", s)),
         None => None,
     };
+    let subject = cmd.args.opt_string("subject");
 
     let expected_messages = cmd.input;
     cmd.args.done()?;
     Ok(VerifyAction {
         sink,
         consistency,
+        subject,
         expected_messages,
     })
 }
@@ -99,16 +102,24 @@ impl Action for VerifyAction {
 
         println!("Verifying results in Kafka topic {}", topic);
 
+        // Sinks publish their schemas under the topic name unless they were
+        // created with a `subject_name`. Consistency topics always use their
+        // topic name.
+        let subject = match (&self.subject, &self.consistency) {
+            (Some(subject), None) => subject,
+            _ => &topic,
+        };
+
         let value_schema = state
             .ccsr_client
-            .get_schema_by_subject(&format!("{}-value", topic))
+            .get_schema_by_subject(&format!("{}-value", subject))
             .await
             .map_err(|e| format!("fetching schema: {}", e))?
             .raw;
 
         let key_schema = state
             .ccsr_client
-            .get_schema_by_subject(&format!("{}-key", topic))
+            .get_schema_by_subject(&format!("{}-key", subject))
             .await
             .ok()
             .map(|key_schema| {